@@ -1,5 +1,8 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::clock;
+use anchor_lang::solana_program::keccak;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount, Transfer};
 
 declare_id!("YourProgramIdHere"); // Replace with your program ID
 
@@ -38,61 +41,143 @@ pub mod supply_chain_trust {
         batch.creator = ctx.accounts.creator.key();
         batch.created_at = clock::Clock::get()?.unix_timestamp;
         batch.exists = true;
+        batch.mint = ctx.accounts.mint.key();
         batch.bump = ctx.bumps.batch;
 
+        let bump = batch.bump;
+        let batch_id_bytes = batch.batch_id.as_bytes().to_vec();
+        let signer_seeds: &[&[u8]] = &[b"batch", batch_id_bytes.as_slice(), &[bump]];
+
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.creator_token_account.to_account_info(),
+                    authority: ctx.accounts.batch.to_account_info(),
+                },
+                &[signer_seeds],
+            ),
+            1,
+        )?;
+
+        let mut batch_log = ctx.accounts.batch_log.load_init()?;
+        batch_log.batch = ctx.accounts.batch.key();
+        batch_log.head = 0;
+        batch_log.count = 0;
+        batch_log.bump = ctx.bumps.batch_log;
+        drop(batch_log);
+
         let program_state = &mut ctx.accounts.program_state;
         program_state.total_batches = program_state.total_batches.checked_add(1).unwrap();
 
         emit!(BatchCreated {
             batch_id,
             creator: ctx.accounts.creator.key(),
-            timestamp: batch.created_at,
+            mint: ctx.accounts.mint.key(),
+            timestamp: ctx.accounts.batch.created_at,
         });
 
         Ok(())
     }
 
-    pub fn log_event(
-        ctx: Context<LogEvent>,
+    pub fn append_event(
+        ctx: Context<AppendEvent>,
         actor: String,
         role: String,
         note: String,
-        first_view_image: String,
-        second_view_image: String,
-        event_hash: String,
+        first_view_image: [u8; 32],
+        second_view_image: [u8; 32],
+        event_hash: [u8; 32],
+        prev_hash: [u8; 32],
     ) -> Result<()> {
         require!(!actor.is_empty(), SupplyChainError::EmptyActor);
         require!(!role.is_empty(), SupplyChainError::EmptyRole);
         require!(!note.is_empty(), SupplyChainError::EmptyNote);
-        require!(!event_hash.is_empty(), SupplyChainError::EmptyEventHash);
+        require!(
+            actor.as_bytes().len() <= EVENT_ACTOR_LEN,
+            SupplyChainError::EventFieldTooLong
+        );
+        require!(
+            role.as_bytes().len() <= EVENT_ROLE_LEN,
+            SupplyChainError::EventFieldTooLong
+        );
+        require!(
+            note.as_bytes().len() <= EVENT_NOTE_LEN,
+            SupplyChainError::EventFieldTooLong
+        );
 
-        let batch = &ctx.accounts.batch;
+        let batch = &mut ctx.accounts.batch;
         require!(batch.exists, SupplyChainError::BatchNotFound);
+        require!(
+            prev_hash == batch.last_event_hash,
+            SupplyChainError::InvalidPrevHash
+        );
+
+        let is_owner = ctx.accounts.logger.key() == ctx.accounts.program_state.owner;
+        if !is_owner {
+            let user_authorization = ctx
+                .accounts
+                .user_authorization
+                .as_ref()
+                .ok_or(SupplyChainError::Unauthorized)?;
+            let expected_key = Pubkey::create_program_address(
+                &[
+                    b"user_auth",
+                    ctx.accounts.logger.key().as_ref(),
+                    &[user_authorization.bump],
+                ],
+                ctx.program_id,
+            )
+            .map_err(|_| error!(SupplyChainError::Unauthorized))?;
+            require_keys_eq!(
+                user_authorization.key(),
+                expected_key,
+                SupplyChainError::Unauthorized
+            );
+            require!(user_authorization.authorized, SupplyChainError::Unauthorized);
+            require!(
+                user_authorization.role.matches(&role),
+                SupplyChainError::Unauthorized
+            );
+        }
 
         let program_state = &mut ctx.accounts.program_state;
         let event_id = program_state.next_event_id;
         program_state.next_event_id = program_state.next_event_id.checked_add(1).unwrap();
 
-        let event = &mut ctx.accounts.event;
-        event.id = event_id;
-        event.actor = actor.clone();
-        event.role = role.clone();
-        event.note = note.clone();
-        event.first_view_image = first_view_image;
-        event.second_view_image = second_view_image;
-        event.event_hash = event_hash;
-        event.logged_by = ctx.accounts.logger.key();
-        event.timestamp = clock::Clock::get()?.unix_timestamp;
-        event.batch = batch.key();
-        event.bump = ctx.bumps.event;
+        let timestamp = clock::Clock::get()?.unix_timestamp;
+        let logged_by = ctx.accounts.logger.key();
+        let chain_hash = chain_event_hash(&prev_hash, &event_hash, &logged_by, timestamp);
+        batch.last_event_hash = chain_hash;
+        batch.append_leaf(event_leaf(event_id, &event_hash, &logged_by, timestamp))?;
+
+        let mut batch_log = ctx.accounts.batch_log.load_mut()?;
+        let slot_index = (batch_log.head as usize) % EVENT_LOG_CAPACITY;
+        let slot = &mut batch_log.slots[slot_index];
+        *slot = EventSlot::default();
+        slot.id = event_id;
+        write_bytes(&mut slot.actor, actor.as_bytes());
+        write_bytes(&mut slot.role, role.as_bytes());
+        write_bytes(&mut slot.note, note.as_bytes());
+        slot.first_view_image = first_view_image;
+        slot.second_view_image = second_view_image;
+        slot.event_hash = event_hash;
+        slot.prev_hash = prev_hash;
+        slot.logged_by = logged_by;
+        slot.timestamp = timestamp;
+
+        batch_log.head = batch_log.head.checked_add(1).unwrap();
+        batch_log.count = batch_log.count.saturating_add(1).min(EVENT_LOG_CAPACITY as u64);
 
         emit!(EventLogged {
             batch_id: batch.batch_id.clone(),
             event_id,
             actor,
             role,
-            logged_by: ctx.accounts.logger.key(),
-            timestamp: event.timestamp,
+            logged_by,
+            timestamp,
+            chain_hash,
         });
 
         Ok(())
@@ -100,7 +185,7 @@ pub mod supply_chain_trust {
 
     pub fn set_user_authorization(
         ctx: Context<SetUserAuthorization>,
-        user: Pubkey,
+        role: Role,
         authorized: bool,
     ) -> Result<()> {
         let program_state = &ctx.accounts.program_state;
@@ -109,12 +194,137 @@ pub mod supply_chain_trust {
             SupplyChainError::Unauthorized
         );
 
+        let user = ctx.accounts.user.key();
         let user_auth = &mut ctx.accounts.user_authorization;
         user_auth.user = user;
+        user_auth.role = role;
         user_auth.authorized = authorized;
         user_auth.bump = ctx.bumps.user_authorization;
 
-        emit!(UserAuthorized { user, authorized });
+        emit!(UserAuthorized {
+            user,
+            role,
+            authorized
+        });
+
+        Ok(())
+    }
+
+    pub fn transfer_custody(
+        ctx: Context<TransferCustody>,
+        note: String,
+        prev_hash: [u8; 32],
+    ) -> Result<()> {
+        require!(!note.is_empty(), SupplyChainError::EmptyNote);
+        require!(
+            note.as_bytes().len() <= EVENT_NOTE_LEN,
+            SupplyChainError::EventFieldTooLong
+        );
+
+        let batch = &mut ctx.accounts.batch;
+        require!(batch.exists, SupplyChainError::BatchNotFound);
+        require!(
+            prev_hash == batch.last_event_hash,
+            SupplyChainError::InvalidPrevHash
+        );
+
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.from_token_account.to_account_info(),
+                    to: ctx.accounts.to_token_account.to_account_info(),
+                    authority: ctx.accounts.current_holder.to_account_info(),
+                },
+            ),
+            1,
+        )?;
+
+        let program_state = &mut ctx.accounts.program_state;
+        let event_id = program_state.next_event_id;
+        program_state.next_event_id = program_state.next_event_id.checked_add(1).unwrap();
+
+        let timestamp = clock::Clock::get()?.unix_timestamp;
+        let current_holder = ctx.accounts.current_holder.key();
+        let new_holder = ctx.accounts.new_holder.key();
+        let event_hash = keccak::hash(new_holder.as_ref()).0;
+        let chain_hash = chain_event_hash(&prev_hash, &event_hash, &current_holder, timestamp);
+        batch.last_event_hash = chain_hash;
+        batch.append_leaf(event_leaf(event_id, &event_hash, &current_holder, timestamp))?;
+
+        let mut batch_log = ctx.accounts.batch_log.load_mut()?;
+        let slot_index = (batch_log.head as usize) % EVENT_LOG_CAPACITY;
+        let slot = &mut batch_log.slots[slot_index];
+        *slot = EventSlot::default();
+        slot.id = event_id;
+        write_bytes(&mut slot.actor, new_holder.to_string().as_bytes());
+        write_bytes(&mut slot.role, b"Custody");
+        write_bytes(&mut slot.note, note.as_bytes());
+        slot.event_hash = event_hash;
+        slot.prev_hash = prev_hash;
+        slot.logged_by = current_holder;
+        slot.timestamp = timestamp;
+
+        batch_log.head = batch_log.head.checked_add(1).unwrap();
+        batch_log.count = batch_log.count.saturating_add(1).min(EVENT_LOG_CAPACITY as u64);
+
+        emit!(CustodyTransferred {
+            batch_id: batch.batch_id.clone(),
+            from: current_holder,
+            to: new_holder,
+            timestamp,
+            chain_hash,
+        });
+
+        Ok(())
+    }
+
+    pub fn verify_inclusion(
+        ctx: Context<VerifyInclusion>,
+        leaf: [u8; 32],
+        proof: Vec<[u8; 32]>,
+        index: u64,
+    ) -> Result<()> {
+        let batch = &ctx.accounts.batch;
+        require!(batch.exists, SupplyChainError::BatchNotFound);
+        require!(index < batch.leaf_count, SupplyChainError::InvalidMerkleIndex);
+
+        let (peak_height, local_index) = Batch::locate_leaf(batch.leaf_count, index)?;
+        require!(
+            proof.len() == peak_height,
+            SupplyChainError::InvalidMerkleProof
+        );
+
+        let mut node = leaf;
+        let mut idx = local_index;
+        for sibling in proof.iter() {
+            node = if idx & 1 == 0 {
+                keccak::hashv(&[&node, sibling]).0
+            } else {
+                keccak::hashv(&[sibling, &node]).0
+            };
+            idx >>= 1;
+        }
+
+        let mut root: Option<[u8; 32]> = None;
+        for h in (0..MAX_MMR_HEIGHT).rev() {
+            if (batch.leaf_count >> h) & 1 == 1 {
+                let peak = if h == peak_height {
+                    node
+                } else {
+                    batch.frontier[h]
+                };
+                root = Some(match root {
+                    None => peak,
+                    Some(acc) => keccak::hashv(&[&peak, &acc]).0,
+                });
+            }
+        }
+
+        require!(
+            root.unwrap_or([0u8; 32]) == batch.merkle_root,
+            SupplyChainError::MerkleVerificationFailed
+        );
 
         Ok(())
     }
@@ -146,6 +356,30 @@ pub struct CreateBatch<'info> {
         bump
     )]
     pub batch: Account<'info, Batch>,
+    #[account(
+        init,
+        payer = creator,
+        mint::decimals = 0,
+        mint::authority = batch,
+        seeds = [b"batch_mint", batch_id.as_bytes()],
+        bump
+    )]
+    pub mint: Account<'info, Mint>,
+    #[account(
+        init,
+        payer = creator,
+        associated_token::mint = mint,
+        associated_token::authority = creator
+    )]
+    pub creator_token_account: Account<'info, TokenAccount>,
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + BatchLog::LEN,
+        seeds = [b"batch_log", batch.key().as_ref()],
+        bump
+    )]
+    pub batch_log: AccountLoader<'info, BatchLog>,
     #[account(
         seeds = [b"program_state"],
         bump = program_state.bump
@@ -153,30 +387,35 @@ pub struct CreateBatch<'info> {
     pub program_state: Account<'info, ProgramState>,
     #[account(mut)]
     pub creator: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
 }
 
 #[derive(Accounts)]
-pub struct LogEvent<'info> {
+pub struct AppendEvent<'info> {
     #[account(
+        mut,
         seeds = [b"batch", batch.batch_id.as_bytes()],
         bump = batch.bump
     )]
     pub batch: Account<'info, Batch>,
     #[account(
-        init,
-        payer = logger,
-        space = 8 + BatchEvent::LEN,
-        seeds = [b"event", batch.key().as_ref(), program_state.next_event_id.to_le_bytes().as_ref()],
-        bump
+        mut,
+        seeds = [b"batch_log", batch.key().as_ref()],
+        bump = batch_log.load()?.bump
     )]
-    pub event: Account<'info, BatchEvent>,
+    pub batch_log: AccountLoader<'info, BatchLog>,
     #[account(
         mut,
         seeds = [b"program_state"],
         bump = program_state.bump
     )]
     pub program_state: Account<'info, ProgramState>,
+    /// Only required when `logger` isn't `program_state.owner` — the owner bypass means
+    /// the owner never needs a `UserAuthorization` PDA of their own to call this.
+    pub user_authorization: Option<Account<'info, UserAuthorization>>,
     #[account(mut)]
     pub logger: Signer<'info>,
     pub system_program: Program<'info, System>,
@@ -193,7 +432,7 @@ pub struct SetUserAuthorization<'info> {
         init,
         payer = owner,
         space = 8 + UserAuthorization::LEN,
-        seeds = [b"user_auth", user_authorization.user.as_ref()],
+        seeds = [b"user_auth", user.key().as_ref()],
         bump
     )]
     pub user_authorization: Account<'info, UserAuthorization>,
@@ -204,6 +443,56 @@ pub struct SetUserAuthorization<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct TransferCustody<'info> {
+    #[account(
+        mut,
+        seeds = [b"batch", batch.batch_id.as_bytes()],
+        bump = batch.bump
+    )]
+    pub batch: Account<'info, Batch>,
+    #[account(
+        mut,
+        seeds = [b"batch_log", batch.key().as_ref()],
+        bump = batch_log.load()?.bump
+    )]
+    pub batch_log: AccountLoader<'info, BatchLog>,
+    #[account(
+        mut,
+        seeds = [b"program_state"],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    #[account(
+        mut,
+        associated_token::mint = batch.mint,
+        associated_token::authority = current_holder
+    )]
+    pub from_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        associated_token::mint = batch.mint,
+        associated_token::authority = new_holder
+    )]
+    pub to_token_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub current_holder: Signer<'info>,
+    /// CHECK: recipient taking custody of the batch's provenance NFT
+    pub new_holder: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct VerifyInclusion<'info> {
+    #[account(
+        seeds = [b"batch", batch.batch_id.as_bytes()],
+        bump = batch.bump
+    )]
+    pub batch: Account<'info, Batch>,
+}
+
 #[account]
 pub struct ProgramState {
     pub owner: Pubkey,
@@ -227,50 +516,211 @@ pub struct Batch {
     pub creator: Pubkey,
     pub created_at: i64,
     pub exists: bool,
+    pub mint: Pubkey,
+    pub last_event_hash: [u8; 32],
+    pub merkle_root: [u8; 32],
+    pub frontier: [[u8; 32]; MAX_MMR_HEIGHT],
+    pub leaf_count: u64,
     pub bump: u8,
 }
 
 impl Batch {
-    pub const LEN: usize = 4 + 100 + 4 + 100 + 4 + 100 + 4 + 200 + 4 + 200 + 32 + 8 + 1 + 1;
-    // batch_id(100) + product_name(100) + sku(100) + origin(100) + first_view_baseline(200) + second_view_baseline(200) + creator + created_at + exists + bump
+    pub const LEN: usize = 4
+        + 100
+        + 4
+        + 100
+        + 4
+        + 100
+        + 4
+        + 200
+        + 4
+        + 200
+        + 32
+        + 8
+        + 1
+        + 32
+        + 32
+        + 32 * MAX_MMR_HEIGHT
+        + 8
+        + 1;
+    // batch_id(100) + product_name(100) + sku(100) + origin(100) + first_view_baseline(200) + second_view_baseline(200)
+    // + creator + created_at + exists + mint + last_event_hash + merkle_root + frontier + leaf_count + bump
+
+    /// Hashes a new leaf into the batch's Merkle Mountain Range accumulator and refreshes `merkle_root`.
+    pub fn append_leaf(&mut self, leaf: [u8; 32]) -> Result<()> {
+        // Every height below MAX_MMR_HEIGHT occupied would overflow `frontier`'s capacity on insert.
+        require!(
+            self.leaf_count < (1u64 << MAX_MMR_HEIGHT) - 1,
+            SupplyChainError::MerkleLogFull
+        );
+
+        let mut carry = leaf;
+        let mut n = self.leaf_count;
+        let mut height = 0usize;
+        while n & 1 == 1 {
+            carry = keccak::hashv(&[&self.frontier[height], &carry]).0;
+            n >>= 1;
+            height += 1;
+        }
+        self.frontier[height] = carry;
+        self.leaf_count += 1;
+        self.merkle_root = Self::bag_peaks(&self.frontier, self.leaf_count);
+        Ok(())
+    }
+
+    /// Folds every occupied peak (the set bits of `leaf_count`) into a single root, highest height first.
+    fn bag_peaks(frontier: &[[u8; 32]; MAX_MMR_HEIGHT], leaf_count: u64) -> [u8; 32] {
+        let mut root: Option<[u8; 32]> = None;
+        for h in (0..MAX_MMR_HEIGHT).rev() {
+            if (leaf_count >> h) & 1 == 1 {
+                root = Some(match root {
+                    None => frontier[h],
+                    Some(acc) => keccak::hashv(&[&frontier[h], &acc]).0,
+                });
+            }
+        }
+        root.unwrap_or([0u8; 32])
+    }
+
+    /// Locates the MMR peak height and local leaf index within that peak's subtree for a global leaf `index`.
+    fn locate_leaf(leaf_count: u64, index: u64) -> Result<(usize, u64)> {
+        let mut start: u64 = 0;
+        for h in (0..MAX_MMR_HEIGHT).rev() {
+            if (leaf_count >> h) & 1 == 1 {
+                let peak_size = 1u64 << h;
+                if index >= start && index < start + peak_size {
+                    return Ok((h, index - start));
+                }
+                start += peak_size;
+            }
+        }
+        err!(SupplyChainError::InvalidMerkleIndex)
+    }
 }
 
-#[account]
-pub struct BatchEvent {
+pub const EVENT_LOG_CAPACITY: usize = 64;
+pub const EVENT_ACTOR_LEN: usize = 64;
+pub const EVENT_ROLE_LEN: usize = 32;
+pub const EVENT_NOTE_LEN: usize = 200;
+
+/// Copies `src` into the head of `dest`, truncating at `dest`'s fixed capacity.
+/// Callers validate field lengths with `require!` before this is reached.
+fn write_bytes(dest: &mut [u8], src: &[u8]) {
+    let len = src.len().min(dest.len());
+    dest[..len].copy_from_slice(&src[..len]);
+}
+
+/// Folds the submitted event into the batch's custody chain: `keccak256(prev_hash || event_hash || logger || timestamp)`.
+fn chain_event_hash(
+    prev_hash: &[u8; 32],
+    event_hash: &[u8; 32],
+    logger: &Pubkey,
+    timestamp: i64,
+) -> [u8; 32] {
+    keccak::hashv(&[prev_hash, event_hash, logger.as_ref(), &timestamp.to_le_bytes()]).0
+}
+
+pub const MAX_MMR_HEIGHT: usize = 32;
+
+/// Canonical Merkle leaf bytes for a single batch event.
+fn event_leaf(event_id: u64, event_hash: &[u8; 32], logger: &Pubkey, timestamp: i64) -> [u8; 32] {
+    keccak::hashv(&[
+        &event_id.to_le_bytes(),
+        event_hash,
+        logger.as_ref(),
+        &timestamp.to_le_bytes(),
+    ])
+    .0
+}
+
+/// One slot in a `BatchLog`'s ring buffer; it is overwritten once `head` wraps past
+/// `EVENT_LOG_CAPACITY` events.
+#[zero_copy]
+#[derive(Default)]
+pub struct EventSlot {
     pub id: u64,
-    pub actor: String,
-    pub role: String,
-    pub note: String,
-    pub first_view_image: String,
-    pub second_view_image: String,
-    pub event_hash: String,
+    pub actor: [u8; EVENT_ACTOR_LEN],
+    pub role: [u8; EVENT_ROLE_LEN],
+    pub note: [u8; EVENT_NOTE_LEN],
+    pub first_view_image: [u8; 32],
+    pub second_view_image: [u8; 32],
+    pub event_hash: [u8; 32],
+    pub prev_hash: [u8; 32],
     pub logged_by: Pubkey,
     pub timestamp: i64,
+}
+
+/// Fixed-capacity, zero-copy event log for a single batch. Events are written
+/// in place at `head % EVENT_LOG_CAPACITY`, so the account never reallocates
+/// or needs a per-event `init`.
+#[account(zero_copy)]
+pub struct BatchLog {
     pub batch: Pubkey,
+    pub head: u64,
+    pub count: u64,
     pub bump: u8,
+    pub _padding: [u8; 7],
+    pub slots: [EventSlot; EVENT_LOG_CAPACITY],
 }
 
-impl BatchEvent {
-    pub const LEN: usize = 8 + 4 + 100 + 4 + 100 + 4 + 200 + 4 + 200 + 4 + 200 + 4 + 64 + 32 + 8 + 32 + 1;
-    // id + actor(100) + role(100) + note(200) + first_view_image(200) + second_view_image(200) + event_hash(64) + logged_by + timestamp + batch + bump
+impl BatchLog {
+    pub const LEN: usize = 32
+        + 8
+        + 8
+        + 1
+        + 7
+        + EVENT_LOG_CAPACITY
+            * (8 + EVENT_ACTOR_LEN + EVENT_ROLE_LEN + EVENT_NOTE_LEN + 32 + 32 + 32 + 32 + 32 + 8);
+    // batch + head + count + bump + padding + (capacity * EventSlot)
 }
 
 #[account]
 pub struct UserAuthorization {
     pub user: Pubkey,
+    pub role: Role,
     pub authorized: bool,
     pub bump: u8,
 }
 
 impl UserAuthorization {
-    pub const LEN: usize = 32 + 1 + 1; // user + authorized + bump
+    pub const LEN: usize = 32 + 1 + 1 + 1; // user + role + authorized + bump
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Role {
+    Manufacturer,
+    Carrier,
+    Distributor,
+    Auditor,
+}
+
+impl Role {
+    /// Checks an `append_event` caller's stored role against the role they claim to act as.
+    pub fn matches(&self, claimed: &str) -> bool {
+        match self {
+            Role::Manufacturer => claimed == "Manufacturer",
+            Role::Carrier => claimed == "Carrier",
+            Role::Distributor => claimed == "Distributor",
+            Role::Auditor => claimed == "Auditor",
+        }
+    }
 }
 
 #[event]
 pub struct BatchCreated {
     pub batch_id: String,
     pub creator: Pubkey,
+    pub mint: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CustodyTransferred {
+    pub batch_id: String,
+    pub from: Pubkey,
+    pub to: Pubkey,
     pub timestamp: i64,
+    pub chain_hash: [u8; 32],
 }
 
 #[event]
@@ -281,11 +731,13 @@ pub struct EventLogged {
     pub role: String,
     pub logged_by: Pubkey,
     pub timestamp: i64,
+    pub chain_hash: [u8; 32],
 }
 
 #[event]
 pub struct UserAuthorized {
     pub user: Pubkey,
+    pub role: Role,
     pub authorized: bool,
 }
 
@@ -301,8 +753,18 @@ pub enum SupplyChainError {
     EmptyRole,
     #[msg("Note cannot be empty")]
     EmptyNote,
-    #[msg("Event hash cannot be empty")]
-    EmptyEventHash,
+    #[msg("Event field exceeds its fixed on-chain capacity")]
+    EventFieldTooLong,
+    #[msg("prev_hash does not match the batch's current custody chain tip")]
+    InvalidPrevHash,
+    #[msg("Leaf index is out of range for this batch's event log")]
+    InvalidMerkleIndex,
+    #[msg("Merkle proof length does not match the target peak's height")]
+    InvalidMerkleProof,
+    #[msg("Recomputed Merkle root does not match the batch's stored root")]
+    MerkleVerificationFailed,
+    #[msg("Batch's Merkle Mountain Range accumulator is full")]
+    MerkleLogFull,
     #[msg("Batch does not exist")]
     BatchNotFound,
     #[msg("Unauthorized")]